@@ -1,6 +1,7 @@
 use wasm_bindgen::prelude::*;
-use regex::Regex;
+use regex::{Regex, RegexSet};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 // Import the `console.log` function from the `console` module
 #[wasm_bindgen]
@@ -22,6 +23,43 @@ pub struct PHIPattern {
     priority: u8,
 }
 
+/// A single detected PHI span, keyed to the original text's UTF-8 byte offsets
+/// so a front-end can overlay highlights/tooltips on the untouched input.
+#[derive(Serialize, Deserialize)]
+pub struct PHIEntity {
+    category: String,
+    start: usize,
+    end: usize,
+    matched_text: String,
+}
+
+/// Redacted text paired with the entity offsets detected against the original
+/// input, for interactive review workflows.
+#[derive(Serialize, Deserialize)]
+pub struct EntityRedactionResult {
+    redacted_text: String,
+    entities: Vec<PHIEntity>,
+}
+
+/// Per-pattern figures from a `benchmark` run.
+#[derive(Serialize, Deserialize)]
+pub struct PatternBenchmark {
+    name: String,
+    match_count: u64,
+    time_ms: f64,
+}
+
+/// Aggregate result of a `benchmark` run over a fixed corpus.
+#[derive(Serialize, Deserialize)]
+pub struct BenchmarkResult {
+    patterns: Vec<PatternBenchmark>,
+    iterations: u32,
+    total_chars: u64,
+    total_time_ms: f64,
+    throughput_chars_per_ms: f64,
+    short_circuit_fraction: f64,
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct RedactionResult {
     redacted_text: String,
@@ -33,6 +71,18 @@ pub struct RedactionResult {
 pub struct FastPHIRedactor {
     patterns: Vec<PHIPattern>,
     compiled_patterns: Vec<Regex>,
+    pattern_set: RegexSet,
+    /// Per-category assignment table for pseudonymization: category label
+    /// (e.g. `NAME`) to a map of matched string to its stable surrogate id.
+    pseudonym_map: HashMap<String, HashMap<String, u32>>,
+}
+
+/// A single accepted redaction span, keyed to the original text's UTF-8 byte
+/// offsets and tagged with the index of the pattern that produced it.
+struct Span {
+    start: usize,
+    end: usize,
+    pattern_index: usize,
 }
 
 #[wasm_bindgen]
@@ -84,19 +134,19 @@ impl FastPHIRedactor {
         let mut sorted_patterns = patterns;
         sorted_patterns.sort_by(|a, b| b.priority.cmp(&a.priority));
 
-        // Compile regex patterns
-        let compiled_patterns: Result<Vec<Regex>, _> = sorted_patterns
-            .iter()
-            .map(|p| Regex::new(&p.pattern))
-            .collect();
+        // Compile the individual regexes plus the combined RegexSet used for the
+        // single-pass pre-filter.
+        let compiled = compile_patterns(&sorted_patterns);
 
-        let compiled_patterns = match compiled_patterns {
-            Ok(patterns) => patterns,
+        let (compiled_patterns, pattern_set) = match compiled {
+            Ok(compiled) => compiled,
             Err(e) => {
                 console_log!("Error compiling regex patterns: {:?}", e);
                 return FastPHIRedactor {
                     patterns: vec![],
                     compiled_patterns: vec![],
+                    pattern_set: RegexSet::empty(),
+                    pseudonym_map: HashMap::new(),
                 };
             }
         };
@@ -104,6 +154,8 @@ impl FastPHIRedactor {
         FastPHIRedactor {
             patterns: sorted_patterns,
             compiled_patterns,
+            pattern_set,
+            pseudonym_map: HashMap::new(),
         }
     }
 
@@ -115,27 +167,12 @@ impl FastPHIRedactor {
             return text.to_string();
         }
 
-        // Fast PHI detection
-        if !self.likely_contains_phi(text) {
-            return text.to_string();
-        }
-
-        let mut redacted = text.to_string();
-        let mut patterns_applied = 0;
-
-        for (i, pattern) in self.compiled_patterns.iter().enumerate() {
-            let original = redacted.clone();
-            redacted = pattern.replace_all(&redacted, &self.patterns[i].replacement).to_string();
-            
-            if redacted != original {
-                patterns_applied += 1;
-            }
-        }
+        let (redacted, patterns_applied) = self.apply_redaction(text);
 
         let end = js_sys::Date::now();
         let processing_time = end - start;
-        
-        console_log!("WASM Redaction completed in {:.2}ms, patterns applied: {}", 
+
+        console_log!("WASM Redaction completed in {:.2}ms, patterns applied: {}",
                     processing_time, patterns_applied);
 
         redacted
@@ -154,27 +191,7 @@ impl FastPHIRedactor {
             return JsValue::from_serde(&result).unwrap();
         }
 
-        // Fast PHI detection
-        if !self.likely_contains_phi(text) {
-            let result = RedactionResult {
-                redacted_text: text.to_string(),
-                processing_time_ms: 0.0,
-                patterns_applied: 0,
-            };
-            return JsValue::from_serde(&result).unwrap();
-        }
-
-        let mut redacted = text.to_string();
-        let mut patterns_applied = 0;
-
-        for (i, pattern) in self.compiled_patterns.iter().enumerate() {
-            let original = redacted.clone();
-            redacted = pattern.replace_all(&redacted, &self.patterns[i].replacement).to_string();
-            
-            if redacted != original {
-                patterns_applied += 1;
-            }
-        }
+        let (redacted, patterns_applied) = self.apply_redaction(text);
 
         let end = js_sys::Date::now();
         let processing_time = end - start;
@@ -188,6 +205,192 @@ impl FastPHIRedactor {
         JsValue::from_serde(&result).unwrap()
     }
 
+    /// Redact `text` with script-aware name detection enabled.
+    ///
+    /// The "Full Name" rule's ASCII-only `[A-Z][a-z]+` capitalization heuristic
+    /// cannot see names written in non-Latin scripts, which is a genuine PHI
+    /// leak for non-English records. This entry point keeps every existing regex
+    /// pattern (so the Latin `\b`-based name rule still fires on Latin names set
+    /// off by spaces or punctuation in a mixed-script document; `\b` is
+    /// Unicode-aware, so a Latin name written directly against CJK characters
+    /// has no word boundary there and is not matched) and additionally scans the
+    /// text to flag
+    /// contiguous Han/Hangul/Kana runs of plausible name length as names. The
+    /// two span sources are merged and overlap-resolved exactly like the
+    /// single-script path before a single rebuild of the output.
+    #[wasm_bindgen]
+    pub fn redact_unicode(&self, text: &str) -> String {
+        if text.is_empty() {
+            return text.to_string();
+        }
+
+        let candidates = self.detect_candidates_unicode(text);
+        build_redacted(text, &candidates)
+    }
+
+    /// Collect overlap-resolved candidates from the regex patterns plus the
+    /// script-aware CJK name tokenizer.
+    fn detect_candidates_unicode(&self, text: &str) -> Vec<Candidate> {
+        // Regex-sourced spans, tagged with their pattern's priority/replacement.
+        let mut candidates: Vec<Candidate> = self
+            .detect_spans(text)
+            .into_iter()
+            .map(|span| {
+                let p = &self.patterns[span.pattern_index];
+                Candidate {
+                    start: span.start,
+                    end: span.end,
+                    priority: p.priority,
+                    replacement: p.replacement.clone(),
+                }
+            })
+            .collect();
+
+        // Borrow the "Full Name" rule's priority/token for CJK name runs so they
+        // sit at the same precedence as Latin names, falling back to sensible
+        // defaults if the rule has been removed from the set.
+        let (name_priority, name_replacement) = self
+            .patterns
+            .iter()
+            .find(|p| p.name == "Full Name")
+            .map(|p| (p.priority, p.replacement.clone()))
+            .unwrap_or((7, "[REDACTED_NAME]".to_string()));
+
+        for (start, end) in cjk_name_spans(text) {
+            candidates.push(Candidate {
+                start,
+                end,
+                priority: name_priority,
+                replacement: name_replacement.clone(),
+            });
+        }
+
+        resolve_overlaps(candidates)
+    }
+
+    /// Detect PHI without redacting, returning an array of
+    /// `{ category, start, end, matched_text }` records keyed to the original
+    /// UTF-8 byte offsets. Useful for rendering highlights over the source text.
+    #[wasm_bindgen]
+    pub fn detect(&self, text: &str) -> JsValue {
+        let entities = self.detect_entities(text);
+        JsValue::from_serde(&entities).unwrap()
+    }
+
+    /// Redact `text` and also return the detected entity offsets. The offsets
+    /// are computed against the original text (not the substituted output), so
+    /// annotations stay valid for a side-by-side original/redacted view.
+    #[wasm_bindgen]
+    pub fn redact_with_entities(&self, text: &str) -> JsValue {
+        let entities = self.detect_entities(text);
+        let (redacted_text, _) = self.apply_redaction(text);
+        let result = EntityRedactionResult {
+            redacted_text,
+            entities,
+        };
+        JsValue::from_serde(&result).unwrap()
+    }
+
+    /// Map the accepted redaction spans onto `PHIEntity` records, carrying the
+    /// pattern name as the category and the original matched slice.
+    fn detect_entities(&self, text: &str) -> Vec<PHIEntity> {
+        self.detect_spans(text)
+            .into_iter()
+            .map(|span| PHIEntity {
+                category: self.patterns[span.pattern_index].name.clone(),
+                start: span.start,
+                end: span.end,
+                matched_text: text[span.start..span.end].to_string(),
+            })
+            .collect()
+    }
+
+    /// Redact `text` using stable surrogates instead of flat tokens.
+    ///
+    /// Each distinct matched string within a category is assigned a stable
+    /// surrogate — `[NAME_1]`, `[PHONE_2]`, … — and the same surrogate is
+    /// reused for every later occurrence, so a reviewer can tell whether two
+    /// redactions refer to the same entity. Assignments accumulate in
+    /// `pseudonym_map`; call `reset_mappings` between independent documents to
+    /// restart numbering.
+    #[wasm_bindgen]
+    pub fn redact_pseudonymized(&mut self, text: &str) -> String {
+        if text.is_empty() {
+            return text.to_string();
+        }
+        self.apply_pseudonymization(text)
+    }
+
+    /// Run `redact_pseudonymized` over a batch. When `shared_mapping` is true a
+    /// single assignment table spans the whole batch, so an entity recurring
+    /// across documents keeps one surrogate; otherwise the table is reset
+    /// before each document so numbering restarts per item.
+    #[wasm_bindgen]
+    pub fn batch_redact_pseudonymized(&mut self, texts: &JsValue, shared_mapping: bool) -> JsValue {
+        let texts: Vec<String> = texts.into_serde().unwrap_or_default();
+
+        if shared_mapping {
+            self.reset_mappings();
+        }
+
+        let results: Vec<String> = texts
+            .iter()
+            .map(|text| {
+                if !shared_mapping {
+                    self.reset_mappings();
+                }
+                self.apply_pseudonymization(text)
+            })
+            .collect();
+
+        JsValue::from_serde(&results).unwrap()
+    }
+
+    /// Expose the current category -> matched-string -> id assignment table for
+    /// audit.
+    #[wasm_bindgen]
+    pub fn get_mapping(&self) -> JsValue {
+        JsValue::from_serde(&self.pseudonym_map).unwrap()
+    }
+
+    /// Clear the pseudonymization assignment table, so surrogate numbering
+    /// restarts for the next document.
+    #[wasm_bindgen]
+    pub fn reset_mappings(&mut self) {
+        self.pseudonym_map.clear();
+    }
+
+    /// Build the pseudonymized output, assigning surrogates as spans are
+    /// encountered. Spans come from `detect_spans`, so overlap resolution and
+    /// priority are identical to the plain redaction path.
+    fn apply_pseudonymization(&mut self, text: &str) -> String {
+        let spans = self.detect_spans(text);
+        if spans.is_empty() {
+            return text.to_string();
+        }
+
+        let mut out = String::with_capacity(text.len());
+        let mut cursor = 0;
+        for span in &spans {
+            let category = category_label(&self.patterns[span.pattern_index].replacement);
+            let matched = &text[span.start..span.end];
+            let id = self.surrogate_id(&category, matched);
+            out.push_str(&text[cursor..span.start]);
+            out.push_str(&format!("[{}_{}]", category, id));
+            cursor = span.end;
+        }
+        out.push_str(&text[cursor..]);
+        out
+    }
+
+    /// Look up (or assign) the stable surrogate id for a matched string within a
+    /// category. Ids start at 1 and increase in first-seen order.
+    fn surrogate_id(&mut self, category: &str, matched: &str) -> u32 {
+        let table = self.pseudonym_map.entry(category.to_string()).or_default();
+        let next = table.len() as u32 + 1;
+        *table.entry(matched.to_string()).or_insert(next)
+    }
+
     #[wasm_bindgen]
     pub fn batch_redact(&self, texts: &JsValue) -> JsValue {
         let texts: Vec<String> = texts.into_serde().unwrap_or_default();
@@ -206,18 +409,153 @@ impl FastPHIRedactor {
         JsValue::from_serde(&results).unwrap()
     }
 
-    fn likely_contains_phi(&self, text: &str) -> bool {
-        if text.len() < 10 {
-            return false;
+    /// Collect the non-overlapping redaction spans for `text`, all taken from the
+    /// original input.
+    ///
+    /// `RegexSet::matches` first tells us which patterns fire at all, which
+    /// short-circuits inputs with no PHI without touching the individual
+    /// engines. For each firing pattern we gather every span via `find_iter`,
+    /// then resolve overlaps greedily: spans are sorted by start offset and, on
+    /// ties, by descending priority, so walking left-to-right and dropping any
+    /// span that overlaps an already-accepted one keeps the highest-priority
+    /// match at each location. All offsets are byte offsets into the original
+    /// `text`, so the result is free of the replace-all cascade the old
+    /// sequential loop suffered from.
+    fn detect_spans(&self, text: &str) -> Vec<Span> {
+        let mut spans: Vec<Span> = Vec::new();
+        for index in self.pattern_set.matches(text).iter() {
+            for m in self.compiled_patterns[index].find_iter(text) {
+                spans.push(Span {
+                    start: m.start(),
+                    end: m.end(),
+                    pattern_index: index,
+                });
+            }
+        }
+
+        spans.sort_by(|a, b| {
+            a.start.cmp(&b.start).then_with(|| {
+                self.patterns[b.pattern_index]
+                    .priority
+                    .cmp(&self.patterns[a.pattern_index].priority)
+            })
+        });
+
+        let mut accepted: Vec<Span> = Vec::new();
+        let mut last_end = 0;
+        for span in spans {
+            if span.start >= last_end {
+                last_end = span.end;
+                accepted.push(span);
+            }
+        }
+        accepted
+    }
+
+    /// Build the redacted string in one pass, copying the slices between
+    /// accepted spans verbatim and emitting each span's replacement token.
+    /// Returns the redacted text and the number of distinct patterns applied.
+    fn apply_redaction(&self, text: &str) -> (String, u32) {
+        let spans = self.detect_spans(text);
+        if spans.is_empty() {
+            return (text.to_string(), 0);
+        }
+
+        let mut out = String::with_capacity(text.len());
+        let mut cursor = 0;
+        let mut applied = vec![false; self.patterns.len()];
+        for span in &spans {
+            out.push_str(&text[cursor..span.start]);
+            out.push_str(&self.patterns[span.pattern_index].replacement);
+            cursor = span.end;
+            applied[span.pattern_index] = true;
+        }
+        out.push_str(&text[cursor..]);
+
+        let patterns_applied = applied.iter().filter(|&&a| a).count() as u32;
+        (out, patterns_applied)
+    }
+
+    /// Add a single `PHIPattern` to the live pattern set.
+    ///
+    /// `spec` is a serialized `PHIPattern` (`{ name, pattern, replacement,
+    /// priority }`). The supplied regex is validated before it is accepted; a
+    /// bad regex leaves the existing set untouched and returns a structured
+    /// error identifying the rejected pattern, rather than silently producing
+    /// an empty set the way the constructor does.
+    #[wasm_bindgen]
+    pub fn add_pattern(&mut self, spec: &JsValue) -> Result<(), JsValue> {
+        let pattern: PHIPattern = spec
+            .into_serde()
+            .map_err(|e| pattern_error("<invalid spec>", &e.to_string()))?;
+        if let Err(e) = Regex::new(&pattern.pattern) {
+            return Err(pattern_error(&pattern.name, &e.to_string()));
+        }
+
+        self.patterns.push(pattern);
+        self.rebuild()
+    }
+
+    /// Replace the entire pattern set from a serialized array of `PHIPattern`s.
+    /// If any regex fails to compile the previous set is preserved and a
+    /// structured error naming the offending pattern is returned.
+    #[wasm_bindgen]
+    pub fn load_patterns(&mut self, json: &JsValue) -> Result<(), JsValue> {
+        let patterns: Vec<PHIPattern> = json
+            .into_serde()
+            .map_err(|e| pattern_error("<invalid array>", &e.to_string()))?;
+
+        for p in &patterns {
+            if let Err(e) = Regex::new(&p.pattern) {
+                return Err(pattern_error(&p.name, &e.to_string()));
+            }
+        }
+
+        let previous = std::mem::replace(&mut self.patterns, patterns);
+        match self.rebuild() {
+            Ok(()) => Ok(()),
+            Err(e) => {
+                // Should be unreachable since every regex was validated above,
+                // but restore the prior set rather than leave it empty.
+                self.patterns = previous;
+                let _ = self.rebuild();
+                Err(e)
+            }
+        }
+    }
+
+    /// Remove every pattern with the given `name`, returning `true` if any was
+    /// removed. The compiled set is kept in sync.
+    #[wasm_bindgen]
+    pub fn remove_pattern(&mut self, name: &str) -> Result<bool, JsValue> {
+        let before = self.patterns.len();
+        self.patterns.retain(|p| p.name != name);
+        let removed = self.patterns.len() != before;
+        if removed {
+            self.rebuild()?;
+        }
+        Ok(removed)
+    }
+
+    /// Export the current pattern set (in priority order) as a serialized array.
+    #[wasm_bindgen]
+    pub fn export_patterns(&self) -> JsValue {
+        JsValue::from_serde(&self.patterns).unwrap()
+    }
+
+    /// Re-sort the patterns by descending priority and recompile both the
+    /// per-pattern engines and the combined `RegexSet` so every field stays in
+    /// the same index space. Called after any mutation of `patterns`.
+    fn rebuild(&mut self) -> Result<(), JsValue> {
+        self.patterns.sort_by(|a, b| b.priority.cmp(&a.priority));
+        match compile_patterns(&self.patterns) {
+            Ok((compiled_patterns, pattern_set)) => {
+                self.compiled_patterns = compiled_patterns;
+                self.pattern_set = pattern_set;
+                Ok(())
+            }
+            Err(e) => Err(pattern_error("<recompile>", &e.to_string())),
         }
-        
-        // Quick checks for common PHI patterns
-        text.contains('@') || // Email
-        text.contains("SSN") || // SSN keyword
-        text.contains("DOB") || // DOB keyword
-        text.contains("MRN") || // Medical record
-        text.contains("(555)") || // Phone pattern
-        text.matches(char::is_numeric).count() > 5 // Lots of numbers
     }
 
     #[wasm_bindgen]
@@ -230,6 +568,211 @@ impl FastPHIRedactor {
         let names: Vec<String> = self.patterns.iter().map(|p| p.name.clone()).collect();
         JsValue::from_serde(&names).unwrap()
     }
+
+    /// Run a fixed corpus through the matcher `iterations` times and report
+    /// where the time goes.
+    ///
+    /// For each input the `RegexSet` pre-filter runs first; inputs it rejects
+    /// are counted as short-circuited and skip all per-pattern scanning. For
+    /// the remainder, every firing pattern is timed individually via
+    /// `find_iter` so pathological regexes (e.g. the phone-number pattern)
+    /// can be spotted by their cumulative time, rather than hidden inside the
+    /// single aggregate `processing_time_ms`. Returns per-pattern match counts
+    /// and time, overall throughput in chars/ms, and the fraction of inputs the
+    /// pre-filter short-circuited.
+    #[wasm_bindgen]
+    pub fn benchmark(&self, texts: &JsValue, iterations: u32) -> JsValue {
+        let texts: Vec<String> = texts.into_serde().unwrap_or_default();
+
+        let mut match_counts = vec![0u64; self.patterns.len()];
+        let mut times = vec![0f64; self.patterns.len()];
+        let mut short_circuited = 0u64;
+        let mut total_chars = 0u64;
+
+        let overall_start = js_sys::Date::now();
+        for _ in 0..iterations {
+            for text in &texts {
+                total_chars += text.chars().count() as u64;
+                let set_matches = self.pattern_set.matches(text);
+                if !set_matches.matched_any() {
+                    short_circuited += 1;
+                    continue;
+                }
+                for index in set_matches.iter() {
+                    let t0 = js_sys::Date::now();
+                    let count = self.compiled_patterns[index].find_iter(text).count();
+                    times[index] += js_sys::Date::now() - t0;
+                    match_counts[index] += count as u64;
+                }
+            }
+        }
+        let total_time_ms = js_sys::Date::now() - overall_start;
+
+        let patterns: Vec<PatternBenchmark> = self
+            .patterns
+            .iter()
+            .enumerate()
+            .map(|(i, p)| PatternBenchmark {
+                name: p.name.clone(),
+                match_count: match_counts[i],
+                time_ms: times[i],
+            })
+            .collect();
+
+        let inputs_processed = texts.len() as u64 * iterations as u64;
+        let result = BenchmarkResult {
+            patterns,
+            iterations,
+            total_chars,
+            total_time_ms,
+            throughput_chars_per_ms: if total_time_ms > 0.0 {
+                total_chars as f64 / total_time_ms
+            } else {
+                0.0
+            },
+            short_circuit_fraction: if inputs_processed > 0 {
+                short_circuited as f64 / inputs_processed as f64
+            } else {
+                0.0
+            },
+        };
+
+        JsValue::from_serde(&result).unwrap()
+    }
+}
+
+/// A redaction candidate carrying its own priority and replacement token, used
+/// by the script-aware path where spans come from both the regex set and the
+/// CJK tokenizer and therefore don't all map back to a single pattern index.
+struct Candidate {
+    start: usize,
+    end: usize,
+    priority: u8,
+    replacement: String,
+}
+
+/// Resolve overlaps among candidates the same way `detect_spans` does: sort by
+/// start offset then descending priority, and greedily keep the first
+/// non-overlapping span at each position.
+fn resolve_overlaps(mut candidates: Vec<Candidate>) -> Vec<Candidate> {
+    candidates.sort_by(|a, b| a.start.cmp(&b.start).then_with(|| b.priority.cmp(&a.priority)));
+
+    let mut accepted: Vec<Candidate> = Vec::new();
+    let mut last_end = 0;
+    for candidate in candidates {
+        if candidate.start >= last_end {
+            last_end = candidate.end;
+            accepted.push(candidate);
+        }
+    }
+    accepted
+}
+
+/// Build the redacted string from pre-resolved, non-overlapping candidates in a
+/// single pass. Candidates are assumed to be sorted by start offset.
+fn build_redacted(text: &str, candidates: &[Candidate]) -> String {
+    if candidates.is_empty() {
+        return text.to_string();
+    }
+
+    let mut out = String::with_capacity(text.len());
+    let mut cursor = 0;
+    for candidate in candidates {
+        out.push_str(&text[cursor..candidate.start]);
+        out.push_str(&candidate.replacement);
+        cursor = candidate.end;
+    }
+    out.push_str(&text[cursor..]);
+    out
+}
+
+/// Detect contiguous runs of CJK name characters (Han, Hangul, Kana) of
+/// plausible personal-name length, returning their UTF-8 byte offset ranges.
+/// CJK names are typically two to four characters, so shorter or longer runs
+/// (e.g. incidental single ideographs or long prose spans) are ignored.
+fn cjk_name_spans(text: &str) -> Vec<(usize, usize)> {
+    const MIN_LEN: usize = 2;
+    const MAX_LEN: usize = 4;
+
+    let mut spans = Vec::new();
+    let mut run_start: Option<usize> = None;
+    let mut count = 0;
+
+    for (i, c) in text.char_indices() {
+        if is_cjk_name_char(c) {
+            if run_start.is_none() {
+                run_start = Some(i);
+                count = 0;
+            }
+            count += 1;
+        } else if let Some(start) = run_start.take() {
+            if (MIN_LEN..=MAX_LEN).contains(&count) {
+                spans.push((start, i));
+            }
+            count = 0;
+        }
+    }
+
+    if let Some(start) = run_start {
+        if (MIN_LEN..=MAX_LEN).contains(&count) {
+            spans.push((start, text.len()));
+        }
+    }
+
+    spans
+}
+
+/// Whether `c` belongs to a script whose names are written without spaces and
+/// so are invisible to the Latin capitalization heuristic.
+fn is_cjk_name_char(c: char) -> bool {
+    matches!(c,
+        '\u{4E00}'..='\u{9FFF}'   // CJK Unified Ideographs (Han)
+        | '\u{3400}'..='\u{4DBF}' // CJK Unified Ideographs Extension A
+        | '\u{3040}'..='\u{309F}' // Hiragana
+        | '\u{30A0}'..='\u{30FF}' // Katakana
+        | '\u{AC00}'..='\u{D7A3}' // Hangul Syllables
+    )
+}
+
+/// Derive a pseudonym category label from a replacement token, e.g.
+/// `[REDACTED_NAME]` -> `NAME`. Tokens that don't follow the `[REDACTED_*]`
+/// convention fall back to their bracket-stripped form.
+fn category_label(replacement: &str) -> String {
+    replacement
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .trim_start_matches("REDACTED_")
+        .to_string()
+}
+
+/// Structured error surfaced to JS when a user-supplied pattern is rejected,
+/// so the caller can tell which pattern failed and why.
+#[derive(Serialize, Deserialize)]
+struct PatternError {
+    error: String,
+    pattern: String,
+}
+
+/// Build a structured `JsValue` error naming the offending pattern.
+fn pattern_error(pattern: &str, error: &str) -> JsValue {
+    let err = PatternError {
+        error: error.to_string(),
+        pattern: pattern.to_string(),
+    };
+    JsValue::from_serde(&err).unwrap_or_else(|_| JsValue::from_str(error))
+}
+
+/// Compile the per-pattern `Regex`es and the combined `RegexSet` from a slice
+/// of patterns assumed to already be sorted by descending priority. Both share
+/// the same index space, so a `RegexSet::matches` index maps directly onto
+/// `compiled_patterns` and `patterns`.
+fn compile_patterns(patterns: &[PHIPattern]) -> Result<(Vec<Regex>, RegexSet), regex::Error> {
+    let compiled_patterns: Vec<Regex> = patterns
+        .iter()
+        .map(|p| Regex::new(&p.pattern))
+        .collect::<Result<_, _>>()?;
+    let pattern_set = RegexSet::new(patterns.iter().map(|p| &p.pattern))?;
+    Ok((compiled_patterns, pattern_set))
 }
 
 // Export a function to create a new redactor